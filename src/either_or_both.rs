@@ -0,0 +1,284 @@
+//! [`EitherOrBoth`], a three-way sibling to [`Either`](crate::Either) for
+//! values that may carry one side, the other, or both — e.g. zipping two
+//! sequences of unequal length, or merging two maps by key.
+
+use crate::Either;
+
+/// A value that is either `A`, `B`, or both.
+pub enum EitherOrBoth<A, B> {
+    /// Both values are present.
+    Both(A, B),
+    /// Only the `A` value is present.
+    Left(A),
+    /// Only the `B` value is present.
+    Right(B),
+}
+
+use EitherOrBoth::{Both, Left, Right};
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns `true` if an `A` value is present, i.e. `self` is `Left` or
+    /// `Both`.
+    pub fn has_left(&self) -> bool {
+        matches!(self, Left(_) | Both(_, _))
+    }
+
+    /// Returns `true` if a `B` value is present, i.e. `self` is `Right` or
+    /// `Both`.
+    pub fn has_right(&self) -> bool {
+        matches!(self, Right(_) | Both(_, _))
+    }
+
+    /// Returns `true` if `self` is `Both`.
+    pub fn is_both(&self) -> bool {
+        matches!(self, Both(_, _))
+    }
+
+    /// Consumes `self` and returns the `A` value, if present (`Left` or
+    /// `Both`).
+    pub fn left(self) -> Option<A> {
+        match self {
+            Left(a) | Both(a, _) => Some(a),
+            Right(_) => None,
+        }
+    }
+
+    /// Consumes `self` and returns the `B` value, if present (`Right` or
+    /// `Both`).
+    pub fn right(self) -> Option<B> {
+        match self {
+            Right(b) | Both(_, b) => Some(b),
+            Left(_) => None,
+        }
+    }
+
+    /// Consumes `self` and returns both values, only if `self` is `Both`.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            Both(a, b) => Some((a, b)),
+            Left(_) | Right(_) => None,
+        }
+    }
+
+    /// Consumes `self` and splits it into its component `Option`s.
+    pub fn left_and_right(self) -> (Option<A>, Option<B>) {
+        match self {
+            Left(a) => (Some(a), None),
+            Right(b) => (None, Some(b)),
+            Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+
+    /// Applies a function to the `A` value, leaving any `B` value untouched.
+    pub fn map_left<F, X>(self, f: F) -> EitherOrBoth<X, B>
+    where
+        F: FnOnce(A) -> X,
+    {
+        match self {
+            Left(a) => Left(f(a)),
+            Right(b) => Right(b),
+            Both(a, b) => Both(f(a), b),
+        }
+    }
+
+    /// Applies a function to the `B` value, leaving any `A` value untouched.
+    pub fn map_right<F, Y>(self, f: F) -> EitherOrBoth<A, Y>
+    where
+        F: FnOnce(B) -> Y,
+    {
+        match self {
+            Left(a) => Left(a),
+            Right(b) => Right(f(b)),
+            Both(a, b) => Both(a, f(b)),
+        }
+    }
+
+    /// Applies `f` to the `A` value and/or `g` to the `B` value, whichever
+    /// are present.
+    pub fn map_any<F, G, X, Y>(self, f: F, g: G) -> EitherOrBoth<X, Y>
+    where
+        F: FnOnce(A) -> X,
+        G: FnOnce(B) -> Y,
+    {
+        match self {
+            Left(a) => Left(f(a)),
+            Right(b) => Right(g(b)),
+            Both(a, b) => Both(f(a), g(b)),
+        }
+    }
+
+    /// Swaps the `A` and `B` sides: `Left` becomes `Right`, `Right` becomes
+    /// `Left`, and `Both(a, b)` becomes `Both(b, a)`.
+    pub fn flip(self) -> EitherOrBoth<B, A> {
+        match self {
+            Left(a) => Right(a),
+            Right(b) => Left(b),
+            Both(a, b) => Both(b, a),
+        }
+    }
+
+    /// Alias for [`flip`](Self::flip), matching the naming of
+    /// [`Either::transpose`](crate::Either::transpose).
+    pub fn transpose(self) -> EitherOrBoth<B, A> {
+        self.flip()
+    }
+
+    /// Resolves the `A` value, falling back to `A::default()` if only a `B`
+    /// value is present.
+    pub fn or_default(self) -> A
+    where
+        A: Default,
+    {
+        self.left().unwrap_or_default()
+    }
+}
+
+impl<A, B> From<Either<A, B>> for EitherOrBoth<A, B> {
+    fn from(either: Either<A, B>) -> Self {
+        either.resolve(Left, Right)
+    }
+}
+
+impl<A, B> Eq for EitherOrBoth<A, B>
+where
+    A: Eq,
+    B: Eq,
+{
+}
+
+impl<A, B> core::cmp::PartialEq for EitherOrBoth<A, B>
+where
+    A: core::cmp::PartialEq,
+    B: core::cmp::PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Left(ax), Left(ay)) => ax == ay,
+            (Right(bx), Right(by)) => bx == by,
+            (Both(ax, bx), Both(ay, by)) => ax == ay && bx == by,
+            _ => false,
+        }
+    }
+}
+
+impl<A, B> core::hash::Hash for EitherOrBoth<A, B>
+where
+    A: core::hash::Hash,
+    B: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Left(a) => a.hash(state),
+            Right(b) => b.hash(state),
+            Both(a, b) => {
+                a.hash(state);
+                b.hash(state);
+            }
+        }
+    }
+}
+
+impl<A, B> core::fmt::Debug for EitherOrBoth<A, B>
+where
+    A: core::fmt::Debug,
+    B: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Left(a) => f.debug_tuple("Left").field(a).finish(),
+            Right(b) => f.debug_tuple("Right").field(b).finish(),
+            Both(a, b) => f.debug_tuple("Both").field(a).field(b).finish(),
+        }
+    }
+}
+
+impl<A, B> Clone for EitherOrBoth<A, B>
+where
+    A: Clone,
+    B: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Left(a) => Left(a.clone()),
+            Right(b) => Right(b.clone()),
+            Both(a, b) => Both(a.clone(), b.clone()),
+        }
+    }
+}
+
+impl<A, B> Copy for EitherOrBoth<A, B>
+where
+    A: Copy,
+    B: Copy,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Left as EitherLeft, Right as EitherRight};
+
+    #[test]
+    fn has_left_and_has_right() {
+        assert!(Left::<i32, i32>(1).has_left());
+        assert!(!Left::<i32, i32>(1).has_right());
+        assert!(Right::<i32, i32>(2).has_right());
+        assert!(!Right::<i32, i32>(2).has_left());
+        assert!(Both(1, 2).has_left());
+        assert!(Both(1, 2).has_right());
+        assert!(Both(1, 2).is_both());
+        assert!(!Left::<i32, i32>(1).is_both());
+    }
+
+    #[test]
+    fn left_right_both_accessors() {
+        assert_eq!(Left::<i32, i32>(1).left(), Some(1));
+        assert_eq!(Right::<i32, i32>(2).left(), None);
+        assert_eq!(Both(1, 2).left(), Some(1));
+
+        assert_eq!(Right::<i32, i32>(2).right(), Some(2));
+        assert_eq!(Left::<i32, i32>(1).right(), None);
+        assert_eq!(Both(1, 2).right(), Some(2));
+
+        assert_eq!(Both(1, 2).both(), Some((1, 2)));
+        assert_eq!(Left::<i32, i32>(1).both(), None);
+
+        assert_eq!(Both(1, 2).left_and_right(), (Some(1), Some(2)));
+        assert_eq!(Left::<i32, i32>(1).left_and_right(), (Some(1), None));
+        assert_eq!(Right::<i32, i32>(2).left_and_right(), (None, Some(2)));
+    }
+
+    #[test]
+    fn map_variants() {
+        assert_eq!(Left::<i32, i32>(1).map_left(|a| a + 1), Left(2));
+        assert_eq!(Right::<i32, i32>(1).map_left(|a| a + 1), Right(1));
+        assert_eq!(Both(1, 2).map_left(|a| a + 1), Both(2, 2));
+
+        assert_eq!(Right::<i32, i32>(1).map_right(|b| b + 1), Right(2));
+        assert_eq!(Both(1, 2).map_right(|b| b + 1), Both(1, 3));
+
+        assert_eq!(Both(1, 2).map_any(|a| a + 1, |b| b * 10), Both(2, 20));
+        assert_eq!(Left::<i32, i32>(1).map_any(|a| a + 1, |b| b * 10), Left(2));
+    }
+
+    #[test]
+    fn flip_and_transpose_swap_sides() {
+        assert_eq!(Left::<i32, &str>(1).flip(), Right(1));
+        assert_eq!(Both(1, "a").flip(), Both("a", 1));
+        assert_eq!(Both(1, "a").transpose(), Both(1, "a").flip());
+    }
+
+    #[test]
+    fn or_default_falls_back_when_left_is_absent() {
+        assert_eq!(Left::<i32, &str>(5).or_default(), 5);
+        assert_eq!(Right::<i32, &str>("x").or_default(), 0);
+    }
+
+    #[test]
+    fn from_either_maps_variants() {
+        let left: EitherOrBoth<i32, &str> = EitherOrBoth::from(EitherLeft(1));
+        let right: EitherOrBoth<i32, &str> = EitherOrBoth::from(EitherRight::<i32, &str>("a"));
+        assert_eq!(left, Left(1));
+        assert_eq!(right, Right("a"));
+    }
+}
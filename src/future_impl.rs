@@ -0,0 +1,52 @@
+//! [`Future`] impl for [`Either`], letting async code that conditionally
+//! awaits one of two differently-typed futures return a single
+//! `Either<L, R>` instead of boxing both arms.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::{Either, Left, Right};
+
+impl<L, R> Future for Either<L, R>
+where
+    L: Future,
+    R: Future<Output = L::Output>,
+{
+    type Output = L::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `Either` holds its payloads inline, so projecting through
+        // `self` requires obtaining a pinned reference to the active
+        // variant's payload by hand. `self` is never moved and neither
+        // variant's payload is ever moved out from behind the `Pin` below.
+        unsafe {
+            match self.get_unchecked_mut() {
+                Left(l) => Pin::new_unchecked(l).poll(cx),
+                Right(r) => Pin::new_unchecked(r).poll(cx),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "use_std")]
+mod tests {
+    use super::*;
+    use core::future::ready;
+    use core::task::Waker;
+
+    #[test]
+    fn polls_whichever_variant_is_active() {
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+
+        let fut: Either<_, core::future::Ready<i32>> = Left(ready(1));
+        let mut fut = Box::pin(fut);
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(1));
+
+        let fut: Either<core::future::Ready<i32>, _> = Right(ready(2));
+        let mut fut = Box::pin(fut);
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(2));
+    }
+}
@@ -0,0 +1,184 @@
+//! Forwarding impls for `std::io`'s reader/writer traits, gated behind the
+//! `use_std` feature.
+//!
+//! These let an `Either<L, R>` stand in for a single concrete stream type
+//! when a function conditionally picks between two (e.g. a real file vs an
+//! in-memory buffer), without boxing either side.
+
+use crate::{Either, Left, Right};
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+impl<L, R> Read for Either<L, R>
+where
+    L: Read,
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Left(l) => l.read(buf),
+            Right(r) => r.read(buf),
+        }
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        match self {
+            Left(l) => l.read_to_end(buf),
+            Right(r) => r.read_to_end(buf),
+        }
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        match self {
+            Left(l) => l.read_to_string(buf),
+            Right(r) => r.read_to_string(buf),
+        }
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        match self {
+            Left(l) => l.read_exact(buf),
+            Right(r) => r.read_exact(buf),
+        }
+    }
+}
+
+impl<L, R> Write for Either<L, R>
+where
+    L: Write,
+    R: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Left(l) => l.write(buf),
+            Right(r) => r.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Left(l) => l.flush(),
+            Right(r) => r.flush(),
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            Left(l) => l.write_all(buf),
+            Right(r) => r.write_all(buf),
+        }
+    }
+
+    fn write_fmt(&mut self, fmt: core::fmt::Arguments<'_>) -> io::Result<()> {
+        match self {
+            Left(l) => l.write_fmt(fmt),
+            Right(r) => r.write_fmt(fmt),
+        }
+    }
+}
+
+impl<L, R> BufRead for Either<L, R>
+where
+    L: BufRead,
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match self {
+            Left(l) => l.fill_buf(),
+            Right(r) => r.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self {
+            Left(l) => l.consume(amt),
+            Right(r) => r.consume(amt),
+        }
+    }
+
+    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+        match self {
+            Left(l) => l.read_until(byte, buf),
+            Right(r) => r.read_until(byte, buf),
+        }
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        match self {
+            Left(l) => l.read_line(buf),
+            Right(r) => r.read_line(buf),
+        }
+    }
+}
+
+impl<L, R> Seek for Either<L, R>
+where
+    L: Seek,
+    R: Seek,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Left(l) => l.seek(pos),
+            Right(r) => r.seek(pos),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_dispatches_to_active_variant() {
+        let mut l: Either<_, Cursor<Vec<u8>>> = Left(Cursor::new(vec![1, 2, 3]));
+        let mut buf = [0u8; 3];
+        l.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [1, 2, 3]);
+
+        let mut r: Either<Cursor<Vec<u8>>, _> = Right(Cursor::new(vec![4, 5, 6]));
+        let mut buf = [0u8; 3];
+        r.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [4, 5, 6]);
+    }
+
+    #[test]
+    fn write_dispatches_to_active_variant() {
+        let mut l: Either<_, Cursor<Vec<u8>>> = Left(Cursor::new(Vec::new()));
+        l.write_all(b"hi").unwrap();
+        l.flush().unwrap();
+        assert_eq!(l.take_left().unwrap().into_inner(), b"hi");
+
+        let mut r: Either<Cursor<Vec<u8>>, _> = Right(Cursor::new(Vec::new()));
+        r.write_all(b"bye").unwrap();
+        r.flush().unwrap();
+        assert_eq!(r.take_right().unwrap().into_inner(), b"bye");
+    }
+
+    #[test]
+    fn bufread_dispatches_to_active_variant() {
+        let mut l: Either<_, Cursor<Vec<u8>>> = Left(Cursor::new(b"one\ntwo\n".to_vec()));
+        let mut line = String::new();
+        l.read_line(&mut line).unwrap();
+        assert_eq!(line, "one\n");
+
+        let mut r: Either<Cursor<Vec<u8>>, _> = Right(Cursor::new(b"three\n".to_vec()));
+        let mut line = String::new();
+        r.read_line(&mut line).unwrap();
+        assert_eq!(line, "three\n");
+    }
+
+    #[test]
+    fn seek_dispatches_to_active_variant() {
+        let mut l: Either<_, Cursor<Vec<u8>>> = Left(Cursor::new(vec![0, 1, 2, 3]));
+        assert_eq!(l.seek(SeekFrom::Start(2)).unwrap(), 2);
+        let mut buf = [0u8; 2];
+        l.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [2, 3]);
+
+        let mut r: Either<Cursor<Vec<u8>>, _> = Right(Cursor::new(vec![0, 1, 2, 3]));
+        assert_eq!(r.seek(SeekFrom::Start(1)).unwrap(), 1);
+        let mut buf = [0u8; 2];
+        r.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [1, 2]);
+    }
+}
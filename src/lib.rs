@@ -2,6 +2,21 @@
 //! with the variants `Left` or `Right` corresponding to each
 //! type respectively.
 
+#![cfg_attr(not(feature = "use_std"), no_std)]
+
+#[cfg(feature = "use_std")]
+mod io_impls;
+
+mod either_or_both;
+pub use either_or_both::EitherOrBoth;
+
+mod future_impl;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "serde")]
+pub use serde_impls::{serde_untagged, serde_untagged_optional};
+
 pub use Either::{Left, Right};
 
 // Note: commonly derived traits (such as `Clone`, `Copy`, etc)
@@ -9,6 +24,7 @@ pub use Either::{Left, Right};
 // derived via macros. This is to allow for types that don't
 // implement these traits.
 // TODO: CONFIRM WHETHER THIS ^  IS NECESSARY.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Either<L, R> {
     /// The first case holds a value of type `L`.
     /// This may be treated as the default case.
@@ -26,7 +42,8 @@ impl<L, R> Either<L, R> {
     /// ```
     /// use eithr::*;
     ///
-    /// let one_or_more = (Left('a'), Right(vec!['a', 'b', 'c']));
+    /// let one_or_more: (Either<char, Vec<char>>, Either<char, Vec<char>>) =
+    ///     (Left('a'), Right(vec!['a', 'b', 'c']));
     /// assert_eq!(one_or_more.0.is_left(), true);
     /// assert_eq!(one_or_more.1.is_left(), false);
     /// ```
@@ -149,6 +166,7 @@ impl<L, R> Either<L, R> {
 
     /// Converts the inner `IntoIterator` value into an `Iterator`, returning
     /// it wrapped in the corresponding `Either` variant.
+    #[allow(clippy::should_implement_trait)]
     pub fn into_iter(self) -> Either<L::IntoIter, R::IntoIter>
     where
         L: IntoIterator,
@@ -161,6 +179,135 @@ impl<L, R> Either<L, R> {
     }
 }
 
+impl<T, L2, R2> Either<(T, L2), (T, R2)> {
+    /// Factors out the shared first component of a tuple carried by both
+    /// variants, returning it alongside an `Either` of the remainders.
+    pub fn factor_first(self) -> (T, Either<L2, R2>) {
+        match self {
+            Left((t, l2)) => (t, Left(l2)),
+            Right((t, r2)) => (t, Right(r2)),
+        }
+    }
+}
+
+impl<T, L2, R2> Either<(L2, T), (R2, T)> {
+    /// Factors out the shared second component of a tuple carried by both
+    /// variants, returning an `Either` of the remainders alongside it.
+    pub fn factor_second(self) -> (Either<L2, R2>, T) {
+        match self {
+            Left((l2, t)) => (Left(l2), t),
+            Right((r2, t)) => (Right(r2), t),
+        }
+    }
+}
+
+impl<L, R> Either<Option<L>, Option<R>> {
+    /// Distributes `Either` over `Option`, collapsing to `None` if the active
+    /// variant's inner `Option` is `None`.
+    pub fn factor_none(self) -> Option<Either<L, R>> {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => r.map(Right),
+        }
+    }
+}
+
+impl<L, R, E> Either<Result<L, E>, Result<R, E>> {
+    /// Distributes `Either` over `Result`, collapsing to `Err` if the active
+    /// variant's inner `Result` is `Err`.
+    pub fn factor_ok(self) -> Result<Either<L, R>, E> {
+        match self {
+            Left(l) => l.map(Left),
+            Right(r) => r.map(Right),
+        }
+    }
+}
+
+impl<T, L, R> Either<Result<T, L>, Result<T, R>> {
+    /// Distributes `Either` over `Result`, collapsing to `Ok` if the active
+    /// variant's inner `Result` is `Ok`.
+    pub fn factor_err(self) -> Result<T, Either<L, R>> {
+        match self {
+            Left(l) => l.map_err(Left),
+            Right(r) => r.map_err(Right),
+        }
+    }
+}
+
+impl<L, R> Iterator for Either<L, R>
+where
+    L: Iterator,
+    R: Iterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Left(l) => l.next(),
+            Right(r) => r.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Left(l) => l.size_hint(),
+            Right(r) => r.size_hint(),
+        }
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        match self {
+            Left(l) => l.fold(init, f),
+            Right(r) => r.fold(init, f),
+        }
+    }
+
+    fn count(self) -> usize {
+        match self {
+            Left(l) => l.count(),
+            Right(r) => r.count(),
+        }
+    }
+}
+
+/// Dispatches `next_back` to whichever variant is active, so an `Either` of
+/// two `DoubleEndedIterator`s can itself be consumed from the back.
+impl<L, R> core::iter::DoubleEndedIterator for Either<L, R>
+where
+    L: core::iter::DoubleEndedIterator,
+    R: core::iter::DoubleEndedIterator<Item = L::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Left(l) => l.next_back(),
+            Right(r) => r.next_back(),
+        }
+    }
+}
+
+impl<L, R> core::iter::ExactSizeIterator for Either<L, R>
+where
+    L: core::iter::ExactSizeIterator,
+    R: core::iter::ExactSizeIterator<Item = L::Item>,
+{
+    fn len(&self) -> usize {
+        match self {
+            Left(l) => l.len(),
+            Right(r) => r.len(),
+        }
+    }
+}
+
+impl<L, R> core::iter::FusedIterator for Either<L, R>
+where
+    L: core::iter::FusedIterator,
+    R: core::iter::FusedIterator<Item = L::Item>,
+{
+}
+
 impl<L, R> Eq for Either<L, R>
 where
     L: Eq,
@@ -168,10 +315,10 @@ where
 {
 }
 
-impl<L, R> std::cmp::PartialEq for Either<L, R>
+impl<L, R> core::cmp::PartialEq for Either<L, R>
 where
-    L: std::cmp::PartialEq,
-    R: std::cmp::PartialEq,
+    L: core::cmp::PartialEq,
+    R: core::cmp::PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
@@ -182,12 +329,46 @@ where
     }
 }
 
-impl<L, R> std::hash::Hash for Either<L, R>
+/// `Left` values order before `Right` values; within a variant, the inner
+/// values are compared.
+impl<L, R> core::cmp::PartialOrd for Either<L, R>
+where
+    L: core::cmp::PartialOrd,
+    R: core::cmp::PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self, other) {
+            (Left(lx), Left(ly)) => lx.partial_cmp(ly),
+            (Right(rx), Right(ry)) => rx.partial_cmp(ry),
+            (Left(_), Right(_)) => Some(core::cmp::Ordering::Less),
+            (Right(_), Left(_)) => Some(core::cmp::Ordering::Greater),
+        }
+    }
+}
+
+/// `Left` values order before `Right` values; within a variant, the inner
+/// values are compared.
+impl<L, R> core::cmp::Ord for Either<L, R>
+where
+    L: core::cmp::Ord,
+    R: core::cmp::Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self, other) {
+            (Left(lx), Left(ly)) => lx.cmp(ly),
+            (Right(rx), Right(ry)) => rx.cmp(ry),
+            (Left(_), Right(_)) => core::cmp::Ordering::Less,
+            (Right(_), Left(_)) => core::cmp::Ordering::Greater,
+        }
+    }
+}
+
+impl<L, R> core::hash::Hash for Either<L, R>
 where
-    L: std::hash::Hash,
-    R: std::hash::Hash,
+    L: core::hash::Hash,
+    R: core::hash::Hash,
 {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         match self {
             Left(l) => l.hash(state),
             Right(r) => r.hash(state),
@@ -195,12 +376,12 @@ where
     }
 }
 
-impl<L, R> std::fmt::Debug for Either<L, R>
+impl<L, R> core::fmt::Debug for Either<L, R>
 where
-    L: std::fmt::Debug,
-    R: std::fmt::Debug,
+    L: core::fmt::Debug,
+    R: core::fmt::Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Left(l) => f.debug_tuple("Left").field(l).finish(),
             Right(r) => f.debug_tuple("Right").field(r).finish(),
@@ -228,6 +409,50 @@ where
 {
 }
 
+/// Forwards `as_ref` to whichever variant is active, so an `Either<L, R>`
+/// can be borrowed as `&str`, `&[u8]`, `&Path`, or any other target both
+/// sides implement `AsRef<Target>` for, regardless of variant.
+///
+/// Note: [`Either`] also has an inherent `as_ref` (returning
+/// `Either<&L, &R>`), which always wins over this trait impl for plain
+/// `.as_ref()` dot-call syntax. To reach this impl, call it through the
+/// trait explicitly, e.g. `AsRef::<str>::as_ref(&either)`.
+impl<L, R, Target> AsRef<Target> for Either<L, R>
+where
+    Target: ?Sized,
+    L: AsRef<Target>,
+    R: AsRef<Target>,
+{
+    fn as_ref(&self) -> &Target {
+        match self {
+            Left(l) => l.as_ref(),
+            Right(r) => r.as_ref(),
+        }
+    }
+}
+
+/// Forwards `as_mut` to whichever variant is active, so an `Either<L, R>`
+/// can be borrowed mutably as any target both sides implement
+/// `AsMut<Target>` for, regardless of variant.
+///
+/// Note: [`Either`] also has an inherent `as_mut` (returning
+/// `Either<&mut L, &mut R>`), which always wins over this trait impl for
+/// plain `.as_mut()` dot-call syntax. To reach this impl, call it through
+/// the trait explicitly, e.g. `AsMut::<str>::as_mut(&mut either)`.
+impl<L, R, Target> AsMut<Target> for Either<L, R>
+where
+    Target: ?Sized,
+    L: AsMut<Target>,
+    R: AsMut<Target>,
+{
+    fn as_mut(&mut self) -> &mut Target {
+        match self {
+            Left(l) => l.as_mut(),
+            Right(r) => r.as_mut(),
+        }
+    }
+}
+
 impl<L> From<Either<L, ()>> for Option<L> {
     fn from(either: Either<L, ()>) -> Self {
         either.resolve(|l| Some(l), |_| None)
@@ -236,8 +461,147 @@ impl<L> From<Either<L, ()>> for Option<L> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn next_dispatches_to_active_variant() {
+        let mut l: Either<_, core::iter::Empty<i32>> = Left(vec![1, 2, 3].into_iter());
+        assert_eq!(l.next(), Some(1));
+        assert_eq!(l.next(), Some(2));
+
+        let mut r: Either<core::iter::Empty<i32>, _> = Right(vec![4, 5].into_iter());
+        assert_eq!(r.next(), Some(4));
+        assert_eq!(r.next(), Some(5));
+        assert_eq!(r.next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn next_back_dispatches_to_active_variant() {
+        let mut l: Either<_, core::iter::Empty<i32>> = Left(vec![1, 2, 3].into_iter());
+        assert_eq!(l.next_back(), Some(3));
+        assert_eq!(l.next_back(), Some(2));
+
+        let mut r: Either<core::iter::Empty<i32>, _> = Right(vec![4, 5].into_iter());
+        assert_eq!(r.next_back(), Some(5));
+        assert_eq!(r.next_back(), Some(4));
+        assert_eq!(r.next_back(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn size_hint_and_len_dispatch_to_active_variant() {
+        let l: Either<_, core::iter::Empty<i32>> = Left(vec![1, 2, 3].into_iter());
+        assert_eq!(l.size_hint(), (3, Some(3)));
+        assert_eq!(l.len(), 3);
+
+        let r: Either<core::iter::Empty<i32>, _> = Right(vec![4, 5].into_iter());
+        assert_eq!(r.size_hint(), (2, Some(2)));
+        assert_eq!(r.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn fold_and_count_dispatch_to_active_variant() {
+        let l: Either<_, core::iter::Empty<i32>> = Left(vec![1, 2, 3].into_iter());
+        assert_eq!(l.fold(String::new(), |acc, x| acc + &x.to_string()), "123");
+
+        let r: Either<core::iter::Empty<i32>, _> = Right(vec![4, 5].into_iter());
+        assert_eq!(r.count(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn as_ref_forwards_through_ufcs() {
+        let l: Either<String, &str> = Left(String::from("hi"));
+        let r: Either<String, &str> = Right("bye");
+        assert_eq!(AsRef::<str>::as_ref(&l), "hi");
+        assert_eq!(AsRef::<str>::as_ref(&r), "bye");
+    }
+
+    #[test]
+    #[cfg(feature = "use_std")]
+    fn as_mut_forwards_through_ufcs() {
+        let mut e: Either<Vec<u8>, Vec<u8>> = Left(vec![1, 2, 3]);
+        AsMut::<[u8]>::as_mut(&mut e)[0] = 9;
+        assert_eq!(e.take_left().unwrap(), vec![9, 2, 3]);
+    }
+
+    #[test]
+    fn factor_first_factors_out_shared_first_component() {
+        let l: Either<(i32, char), (i32, bool)> = Left((1, 'a'));
+        assert_eq!(l.factor_first(), (1, Left('a')));
+
+        let r: Either<(i32, char), (i32, bool)> = Right((2, true));
+        assert_eq!(r.factor_first(), (2, Right(true)));
+    }
+
+    #[test]
+    fn factor_second_factors_out_shared_second_component() {
+        let l: Either<(char, i32), (bool, i32)> = Left(('a', 1));
+        assert_eq!(l.factor_second(), (Left('a'), 1));
+
+        let r: Either<(char, i32), (bool, i32)> = Right((true, 2));
+        assert_eq!(r.factor_second(), (Right(true), 2));
+    }
+
+    #[test]
+    fn factor_none_collapses_to_none_on_inner_none() {
+        let l: Either<Option<i32>, Option<bool>> = Left(Some(1));
+        assert_eq!(l.factor_none(), Some(Left(1)));
+
+        let l: Either<Option<i32>, Option<bool>> = Left(None);
+        assert_eq!(l.factor_none(), None);
+
+        let r: Either<Option<i32>, Option<bool>> = Right(Some(true));
+        assert_eq!(r.factor_none(), Some(Right(true)));
+
+        let r: Either<Option<i32>, Option<bool>> = Right(None);
+        assert_eq!(r.factor_none(), None);
+    }
+
+    #[test]
+    fn factor_ok_collapses_to_err_on_inner_err() {
+        let l: Either<Result<i32, &str>, Result<bool, &str>> = Left(Ok(1));
+        assert_eq!(l.factor_ok(), Ok(Left(1)));
+
+        let l: Either<Result<i32, &str>, Result<bool, &str>> = Left(Err("bad"));
+        assert_eq!(l.factor_ok(), Err("bad"));
+
+        let r: Either<Result<i32, &str>, Result<bool, &str>> = Right(Ok(true));
+        assert_eq!(r.factor_ok(), Ok(Right(true)));
+
+        let r: Either<Result<i32, &str>, Result<bool, &str>> = Right(Err("bad"));
+        assert_eq!(r.factor_ok(), Err("bad"));
+    }
+
+    #[test]
+    fn factor_err_collapses_to_ok_on_inner_ok() {
+        let l: Either<Result<i32, i32>, Result<i32, bool>> = Left(Ok(1));
+        assert_eq!(l.factor_err(), Ok(1));
+
+        let l: Either<Result<i32, i32>, Result<i32, bool>> = Left(Err(9));
+        assert_eq!(l.factor_err(), Err(Left(9)));
+
+        let r: Either<Result<i32, i32>, Result<i32, bool>> = Right(Ok(2));
+        assert_eq!(r.factor_err(), Ok(2));
+
+        let r: Either<Result<i32, i32>, Result<i32, bool>> = Right(Err(false));
+        assert_eq!(r.factor_err(), Err(Right(false)));
+    }
+
+    #[test]
+    fn ord_orders_left_before_right() {
+        let l: Either<i32, i32> = Left(100);
+        let r: Either<i32, i32> = Right(-100);
+        assert!(l < r);
+        assert!(Either::<i32, i32>::Left(1) < Either::Left(2));
+        assert!(Either::<i32, i32>::Right(1) < Either::Right(2));
+    }
 }
@@ -0,0 +1,165 @@
+//! Alternate `serde` representations for [`Either`], for use with
+//! `#[serde(with = ...)]` when the default tagged representation
+//! (`{"Left": ...}` / `{"Right": ...}`) doesn't match an external schema.
+
+use crate::Either;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes an `Either<L, R>` as the bare inner value, with no
+/// `Left`/`Right` tag. On deserialize, `L` is tried first and `R` is used as
+/// the fallback.
+///
+/// Use via `#[serde(with = "eithr::serde_untagged")]` on a field of type
+/// `Either<L, R>`.
+pub mod serde_untagged {
+    use super::*;
+
+    pub fn serialize<L, R, S>(either: &Either<L, R>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        L: Serialize,
+        R: Serialize,
+        S: Serializer,
+    {
+        match either {
+            Either::Left(l) => l.serialize(serializer),
+            Either::Right(r) => r.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Either<L, R>, D::Error>
+    where
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        Repr::deserialize(deserializer).map(|repr| match repr {
+            Repr::Left(l) => Either::Left(l),
+            Repr::Right(r) => Either::Right(r),
+        })
+    }
+}
+
+/// Like [`serde_untagged`], but for an `Option<Either<L, R>>` field, so a
+/// key present with a `null` value deserializes to `None` instead of an
+/// error.
+///
+/// Use via `#[serde(with = "eithr::serde_untagged_optional")]`. `with` only
+/// runs when the key is present in the input at all, so to also accept a
+/// *missing* key (rather than erroring on it), the field needs
+/// `#[serde(default)]` in addition to this attribute.
+pub mod serde_untagged_optional {
+    use super::*;
+
+    pub fn serialize<L, R, S>(
+        either: &Option<Either<L, R>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        L: Serialize,
+        R: Serialize,
+        S: Serializer,
+    {
+        match either {
+            Some(Either::Left(l)) => serializer.serialize_some(l),
+            Some(Either::Right(r)) => serializer.serialize_some(r),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, L, R, D>(deserializer: D) -> Result<Option<Either<L, R>>, D::Error>
+    where
+        L: Deserialize<'de>,
+        R: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<L, R> {
+            Left(L),
+            Right(R),
+        }
+
+        Option::<Repr<L, R>>::deserialize(deserializer).map(|opt| {
+            opt.map(|repr| match repr {
+                Repr::Left(l) => Either::Left(l),
+                Repr::Right(r) => Either::Right(r),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "use_std")]
+mod tests {
+    use crate::{Either, Left, Right};
+
+    #[test]
+    fn tagged_round_trip() {
+        let l: Either<i32, String> = Left(1);
+        let r: Either<i32, String> = Right("hi".into());
+
+        assert_eq!(serde_json::to_string(&l).unwrap(), r#"{"Left":1}"#);
+        assert_eq!(serde_json::to_string(&r).unwrap(), r#"{"Right":"hi"}"#);
+        assert_eq!(
+            serde_json::from_str::<Either<i32, String>>(r#"{"Left":1}"#).unwrap(),
+            l
+        );
+        assert_eq!(
+            serde_json::from_str::<Either<i32, String>>(r#"{"Right":"hi"}"#).unwrap(),
+            r
+        );
+    }
+
+    #[test]
+    fn untagged_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::serde_untagged")]
+            value: Either<i32, String>,
+        }
+
+        let w = Wrapper { value: Left(1) };
+        let json = serde_json::to_string(&w).unwrap();
+        assert_eq!(json, r#"{"value":1}"#);
+        let back: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.value, Left(1));
+
+        let w = Wrapper {
+            value: Right("hi".into()),
+        };
+        let json = serde_json::to_string(&w).unwrap();
+        assert_eq!(json, r#"{"value":"hi"}"#);
+        let back: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.value, Right("hi".into()));
+    }
+
+    #[test]
+    fn untagged_optional_round_trip() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Wrapper {
+            #[serde(with = "crate::serde_untagged_optional", default)]
+            value: Option<Either<i32, String>>,
+        }
+
+        let w = Wrapper { value: Some(Left(1)) };
+        let json = serde_json::to_string(&w).unwrap();
+        assert_eq!(json, r#"{"value":1}"#);
+        let back: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.value, Some(Left(1)));
+
+        let back: Wrapper = serde_json::from_str(r#"{"value":null}"#).unwrap();
+        assert_eq!(back.value, None);
+
+        // Missing key needs `#[serde(default)]`, added above, since `with`
+        // never runs when the key is absent.
+        let back: Wrapper = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(back.value, None);
+    }
+}